@@ -49,6 +49,10 @@ use crate::{types::chainstate::StacksBlockId, types::StacksEpochId};
 use crate::vm::costs::cost_functions::ClarityCostFunction;
 use crate::vm::version::ClarityVersion;
 
+use stacks_common::address::{
+    C32_ADDRESS_VERSION_MAINNET_MULTISIG, C32_ADDRESS_VERSION_MAINNET_SINGLESIG,
+    C32_ADDRESS_VERSION_TESTNET_MULTISIG, C32_ADDRESS_VERSION_TESTNET_SINGLESIG,
+};
 use stacks_common::consts::CHAIN_ID_TESTNET;
 
 use serde::Serialize;
@@ -189,6 +193,36 @@ pub struct EventBatch {
     pub events: Vec<StacksTransactionEvent>,
 }
 
+/// The address version bytes that `is-standard`, `principal-destruct?` and
+/// `principal-construct?` treat as belonging to the network a transaction executes on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkAddressVersions {
+    pub singlesig: u8,
+    pub multisig: u8,
+}
+
+impl NetworkAddressVersions {
+    /// The address versions used by the Stacks mainnet or testnet.
+    pub fn for_network(mainnet: bool) -> NetworkAddressVersions {
+        if mainnet {
+            NetworkAddressVersions {
+                singlesig: C32_ADDRESS_VERSION_MAINNET_SINGLESIG,
+                multisig: C32_ADDRESS_VERSION_MAINNET_MULTISIG,
+            }
+        } else {
+            NetworkAddressVersions {
+                singlesig: C32_ADDRESS_VERSION_TESTNET_SINGLESIG,
+                multisig: C32_ADDRESS_VERSION_TESTNET_MULTISIG,
+            }
+        }
+    }
+
+    /// Returns true if `version` is one of this network's address versions.
+    pub fn matches(&self, version: u8) -> bool {
+        version == self.singlesig || version == self.multisig
+    }
+}
+
 /** GlobalContext represents the outermost context for a single transaction's
      execution. It tracks an asset changes that occurred during the
      processing of the transaction, whether or not the current context is read_only,
@@ -206,6 +240,9 @@ pub struct GlobalContext<'a, 'hooks> {
     pub epoch_id: StacksEpochId,
     /// This is the chain ID of the transaction
     pub chain_id: u32,
+    /// These are the address versions considered "standard" on this chain. They are derived
+    /// from `mainnet` unless overridden (which only tests do).
+    pub address_versions: NetworkAddressVersions,
     pub eval_hooks: Option<Vec<&'hooks mut dyn EvalHook>>,
 }
 
@@ -1593,6 +1630,7 @@ impl<'a, 'hooks> GlobalContext<'a, 'hooks> {
             mainnet,
             epoch_id,
             chain_id,
+            address_versions: NetworkAddressVersions::for_network(mainnet),
             eval_hooks: None,
         }
     }
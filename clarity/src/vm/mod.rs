@@ -58,8 +58,8 @@ use crate::types::StacksEpochId;
 pub use crate::vm::database::clarity_db::StacksEpoch;
 
 use crate::vm::callables::CallableType;
-use crate::vm::contexts::GlobalContext;
 pub use crate::vm::contexts::{CallStack, ContractContext, Environment, LocalContext};
+use crate::vm::contexts::{GlobalContext, NetworkAddressVersions};
 use crate::vm::costs::{
     cost_functions, runtime_cost, CostOverflowingMath, CostTracker, LimitedCostTracker,
     MemoryConsumer,
@@ -516,6 +516,30 @@ pub fn execute_on_network(program: &str, use_mainnet: bool) -> Result<Option<Val
     epoch_205_result
 }
 
+/// Describes the chain a test program runs against: whether it is mainnet, its chain ID, and
+/// which address versions count as standard on it.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkDescriptor {
+    pub mainnet: bool,
+    pub chain_id: u32,
+    pub address_versions: NetworkAddressVersions,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl NetworkDescriptor {
+    /// The Stacks mainnet or testnet, with its usual chain ID and address versions.
+    pub fn from_mainnet(mainnet: bool) -> NetworkDescriptor {
+        use crate::vm::tests::test_only_mainnet_to_chain_id;
+
+        NetworkDescriptor {
+            mainnet,
+            chain_id: test_only_mainnet_to_chain_id(mainnet),
+            address_versions: NetworkAddressVersions::for_network(mainnet),
+        }
+    }
+}
+
 /// Runs `program` in a test environment with the provided parameters.
 #[cfg(any(test, feature = "testing"))]
 pub fn execute_with_parameters(
@@ -524,22 +548,39 @@ pub fn execute_with_parameters(
     epoch: StacksEpochId,
     ast_rules: ast::ASTRules,
     use_mainnet: bool,
+) -> Result<Option<Value>> {
+    execute_against_version_and_network(
+        program,
+        clarity_version,
+        epoch,
+        ast_rules,
+        &NetworkDescriptor::from_mainnet(use_mainnet),
+    )
+}
+
+/// Runs `program` in a test environment on the chain described by `network`.
+#[cfg(any(test, feature = "testing"))]
+pub fn execute_against_version_and_network(
+    program: &str,
+    clarity_version: ClarityVersion,
+    epoch: StacksEpochId,
+    ast_rules: ast::ASTRules,
+    network: &NetworkDescriptor,
 ) -> Result<Option<Value>> {
     use crate::vm::database::MemoryBackingStore;
-    use crate::vm::tests::test_only_mainnet_to_chain_id;
 
     let contract_id = QualifiedContractIdentifier::transient();
     let mut contract_context = ContractContext::new(contract_id.clone(), clarity_version);
     let mut marf = MemoryBackingStore::new();
     let conn = marf.as_clarity_db();
-    let chain_id = test_only_mainnet_to_chain_id(use_mainnet);
     let mut global_context = GlobalContext::new(
-        use_mainnet,
-        chain_id,
+        network.mainnet,
+        network.chain_id,
         conn,
         LimitedCostTracker::new_free(),
         epoch,
     );
+    global_context.address_versions = network.address_versions;
     global_context.execute(|g| {
         let parsed = ast::build_ast_with_rules(
             &contract_id,
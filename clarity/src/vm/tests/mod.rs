@@ -13,10 +13,12 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
-use crate::vm::contexts::OwnedEnvironment;
+use crate::vm::ast::ASTRules;
+use crate::vm::contexts::{ContractContext, OwnedEnvironment};
+use crate::vm::costs::{ExecutionCost, LimitedCostTracker};
 use crate::vm::database::MemoryBackingStore;
 use crate::vm::errors::Error;
-use crate::vm::types::Value;
+use crate::vm::types::{QualifiedContractIdentifier, StandardPrincipalData, Value};
 
 use stacks_common::types::StacksEpochId;
 
@@ -152,6 +154,68 @@ impl TopLevelMemoryEnvironmentGenerator {
     }
 }
 
+/// Executes a series of snippets against one persisted `MemoryBackingStore`, so that a contract
+/// deployed by one call can be called from the next. Contracts are deployed, and snippets are
+/// evaluated, by the transient principal, so `.name` refers to a contract deployed here.
+pub struct TestEnvironment<'a> {
+    owned_env: OwnedEnvironment<'a, 'a>,
+    version: ClarityVersion,
+}
+
+impl<'a> TestEnvironment<'a> {
+    pub fn new(
+        store: &'a mut MemoryBackingStore,
+        version: ClarityVersion,
+        epoch: StacksEpochId,
+        cost_tracker: LimitedCostTracker,
+        use_mainnet: bool,
+    ) -> TestEnvironment<'a> {
+        let owned_env = OwnedEnvironment::new_cost_limited(
+            use_mainnet,
+            test_only_mainnet_to_chain_id(use_mainnet),
+            store.as_clarity_db(),
+            cost_tracker,
+            epoch,
+        );
+        TestEnvironment { owned_env, version }
+    }
+
+    /// Deploys `src` as the contract `name`, returning its identifier.
+    pub fn deploy_contract(
+        &mut self,
+        name: &str,
+        src: &str,
+    ) -> Result<QualifiedContractIdentifier, Error> {
+        let contract_id = QualifiedContractIdentifier::local(name)?;
+        self.owned_env.initialize_versioned_contract(
+            contract_id.clone(),
+            self.version,
+            src,
+            None,
+            ASTRules::PrecheckSize,
+        )?;
+        Ok(contract_id)
+    }
+
+    /// Evaluates the first expression in `src`, committing any changes it makes.
+    pub fn eval(&mut self, src: &str) -> Result<Value, Error> {
+        let (value, _, _) = self.owned_env.execute_in_env(
+            StandardPrincipalData::transient().into(),
+            None,
+            Some(ContractContext::new(
+                QualifiedContractIdentifier::transient(),
+                self.version,
+            )),
+            |exec_env| exec_env.eval_raw_with_rules(src, ASTRules::PrecheckSize),
+        )?;
+        Ok(value)
+    }
+
+    pub fn get_cost_total(&self) -> ExecutionCost {
+        self.owned_env.get_cost_total()
+    }
+}
+
 /// Determine whether or not to use the testnet or mainnet chain ID, given whether or not the
 /// caller expects to use mainnet or testnet.
 ///
@@ -1,8 +1,12 @@
 use crate::vm::ast::ASTRules;
-use crate::vm::execute_with_parameters;
+use crate::vm::contexts::NetworkAddressVersions;
+use crate::vm::costs::LimitedCostTracker;
+use crate::vm::database::MemoryBackingStore;
+use crate::vm::tests::TestEnvironment;
 use crate::vm::types::TypeSignature::PrincipalType;
 use crate::vm::types::{ASCIIData, BuffData, CharType, SequenceData, Value};
 use crate::vm::ClarityVersion;
+use crate::vm::{execute_against_version_and_network, execute_with_parameters, NetworkDescriptor};
 
 use crate::vm::errors::CheckErrors;
 use crate::vm::types::{
@@ -12,6 +16,7 @@ use crate::vm::types::{
 use stacks_common::types::StacksEpochId;
 
 use crate::vm::functions::principals::PrincipalConstructErrorCode;
+use crate::vm::representations::CONTRACT_MAX_NAME_LENGTH;
 
 use stacks_common::util::hash::hex_bytes;
 
@@ -138,6 +143,7 @@ fn test_simple_is_standard_testnet_cases() {
     );
 }
 
+#[test]
 fn test_simple_is_standard_mainnet_cases() {
     let mainnet_addr_test = "(is-standard 'SP3X6QWWETNBZWGBK6DRGTR1KX50S74D3433WDGJY)";
     assert_eq!(
@@ -191,7 +197,7 @@ fn test_simple_is_standard_mainnet_cases() {
         .unwrap()
     );
 
-    let mainnet_addr_test = "(is-standard 'SM3X6QWWETNBZWGBK6DRGTR1KX50S74D3433WDGJY)";
+    let mainnet_addr_test = "(is-standard 'SM3X6QWWETNBZWGBK6DRGTR1KX50S74D341M9C5X7)";
     assert_eq!(
         Value::Bool(true),
         execute_with_parameters(
@@ -217,7 +223,7 @@ fn test_simple_is_standard_mainnet_cases() {
         .unwrap()
     );
 
-    let mainnet_addr_test = "(is-standard 'SM3X6QWWETNBZWGBK6DRGTR1KX50S74D3433WDGJY.tokens)";
+    let mainnet_addr_test = "(is-standard 'SM3X6QWWETNBZWGBK6DRGTR1KX50S74D341M9C5X7.tokens)";
     assert_eq!(
         Value::Bool(true),
         execute_with_parameters(
@@ -839,6 +845,36 @@ fn test_principal_construct_good() {
         .unwrap()
         .unwrap()
     );
+
+    // A contract name of exactly the maximum length, on both networks.
+    let long_name = "a123456789b123456789c123456789d123456789";
+    assert_eq!(long_name.len(), CONTRACT_MAX_NAME_LENGTH);
+    for (version, mainnet) in [(0x16, true), (0x1a, false)] {
+        let input = format!(
+            r#"(principal-construct? 0x{:02x} 0xfa6bf38ed557fe417333710d6033e9419391a320 "{}")"#,
+            version, long_name
+        );
+        assert_eq!(
+            Value::Response(ResponseData {
+                committed: true,
+                data: Box::new(Value::Principal(PrincipalData::Contract(
+                    QualifiedContractIdentifier::new(
+                        StandardPrincipalData(version, transfer_buffer),
+                        long_name.try_into().unwrap()
+                    )
+                )))
+            }),
+            execute_with_parameters(
+                &input,
+                ClarityVersion::Clarity2,
+                StacksEpochId::Epoch21,
+                ASTRules::PrecheckSize,
+                mainnet
+            )
+            .unwrap()
+            .unwrap()
+        );
+    }
 }
 
 /// Creates a `Principal`-type `Value` from string-based byte representations.
@@ -1056,6 +1092,34 @@ fn test_principal_construct_response_errors() {
         }),
     );
 
+    // Hash key part is empty, should have length 20. `(buff 0)` type-checks as `(buff 20)`, so
+    // the error is signaled in the returned Response.
+    let input = r#"(principal-construct? 0x16 0x)"#;
+    assert_eq!(
+        execute_with_parameters(
+            input,
+            ClarityVersion::Clarity2,
+            StacksEpochId::Epoch21,
+            ASTRules::PrecheckSize,
+            false
+        )
+        .unwrap()
+        .unwrap(),
+        Value::Response(ResponseData {
+            committed: false,
+            data: Box::new(Value::Tuple(
+                TupleData::from_data(vec![
+                    (
+                        "error_code".into(),
+                        Value::UInt(PrincipalConstructErrorCode::BUFFER_LENGTH as u128)
+                    ),
+                    ("value".into(), Value::none()),
+                ])
+                .expect("FAIL: Failed to initialize tuple."),
+            )),
+        }),
+    );
+
     // Version byte is too small, should have length 1. This error is signaled in the returned
     // Response.
     let input = r#"(principal-construct? 0x 0x0102030405060708091011121314151617181920)"#;
@@ -1164,4 +1228,343 @@ fn test_principal_construct_response_errors() {
         .unwrap()
         .unwrap()
     );
+
+    // The contract name is not a valid contract name, run on mainnet.
+    let input = r#"(principal-construct? 0x14 0x0102030405060708091011121314151617181920 "foo[")"#;
+    assert_eq!(
+        Value::Response(ResponseData {
+            committed: false,
+            data: Box::new(Value::Tuple(
+                TupleData::from_data(vec![
+                    (
+                        "error_code".into(),
+                        Value::UInt(PrincipalConstructErrorCode::CONTRACT_NAME as u128)
+                    ),
+                    ("value".into(), Value::none()),
+                ])
+                .expect("FAIL: Failed to initialize tuple."),
+            )),
+        }),
+        execute_with_parameters(
+            input,
+            ClarityVersion::Clarity2,
+            StacksEpochId::Epoch21,
+            ASTRules::PrecheckSize,
+            true
+        )
+        .unwrap()
+        .unwrap()
+    );
+
+    // The contract name must start with a letter
+    let input =
+        r#"(principal-construct? 0x16 0x0102030405060708091011121314151617181920 "1-contract")"#;
+    assert_eq!(
+        Value::Response(ResponseData {
+            committed: false,
+            data: Box::new(Value::Tuple(
+                TupleData::from_data(vec![
+                    (
+                        "error_code".into(),
+                        Value::UInt(PrincipalConstructErrorCode::CONTRACT_NAME as u128)
+                    ),
+                    ("value".into(), Value::none()),
+                ])
+                .expect("FAIL: Failed to initialize tuple."),
+            )),
+        }),
+        execute_with_parameters(
+            input,
+            ClarityVersion::Clarity2,
+            StacksEpochId::Epoch21,
+            ASTRules::PrecheckSize,
+            true
+        )
+        .unwrap()
+        .unwrap()
+    );
+}
+
+#[test]
+// Short buffers are signaled in the returned Response, so a contract can recover from them with
+// `match` instead of aborting.
+fn test_principal_construct_buffer_length_errors_are_catchable() {
+    // Hash bytes too short.
+    let input = r#"(match (principal-construct? 0x16 0x01020304050607080910111213141516171819)
+        constructed u999
+        err-data (get error_code err-data))"#;
+    assert_eq!(
+        Value::UInt(PrincipalConstructErrorCode::BUFFER_LENGTH as u128),
+        execute_with_parameters(
+            input,
+            ClarityVersion::Clarity2,
+            StacksEpochId::Epoch21,
+            ASTRules::PrecheckSize,
+            false
+        )
+        .unwrap()
+        .unwrap()
+    );
+
+    // Version byte too short.
+    let input = r#"(match (principal-construct? 0x 0x0102030405060708091011121314151617181920)
+        constructed u999
+        err-data (get error_code err-data))"#;
+    assert_eq!(
+        Value::UInt(PrincipalConstructErrorCode::BUFFER_LENGTH as u128),
+        execute_with_parameters(
+            input,
+            ClarityVersion::Clarity2,
+            StacksEpochId::Epoch21,
+            ASTRules::PrecheckSize,
+            false
+        )
+        .unwrap()
+        .unwrap()
+    );
+
+    // Buffers of exactly the right length still take the `ok` branch.
+    let input = r#"(match (principal-construct? 0x1a 0x0102030405060708091011121314151617181920)
+        constructed u999
+        err-data (get error_code err-data))"#;
+    assert_eq!(
+        Value::UInt(999),
+        execute_with_parameters(
+            input,
+            ClarityVersion::Clarity2,
+            StacksEpochId::Epoch21,
+            ASTRules::PrecheckSize,
+            false
+        )
+        .unwrap()
+        .unwrap()
+    );
+}
+
+#[test]
+// A private network with its own address versions: the principal natives should accept exactly
+// those versions as standard, and reject both mainnet and testnet ones.
+fn test_principals_on_custom_network() {
+    let network = NetworkDescriptor {
+        mainnet: false,
+        chain_id: 0x12345678,
+        address_versions: NetworkAddressVersions {
+            singlesig: 0x05,
+            multisig: 0x06,
+        },
+    };
+    let run = |program: &str| {
+        execute_against_version_and_network(
+            program,
+            ClarityVersion::Clarity2,
+            StacksEpochId::Epoch21,
+            ASTRules::PrecheckSize,
+            &network,
+        )
+        .unwrap()
+        .unwrap()
+    };
+
+    // Both custom versions construct successfully.
+    for version in ["05", "06"] {
+        let input = format!(
+            "(principal-construct? 0x{} 0x0102030405060708091011121314151617181920)",
+            version
+        );
+        assert_eq!(
+            Value::Response(ResponseData {
+                committed: true,
+                data: Box::new(create_principal_from_strings(
+                    version,
+                    "0102030405060708091011121314151617181920",
+                    None
+                )),
+            }),
+            run(&input)
+        );
+    }
+
+    // A testnet version byte is foreign to this network.
+    assert_eq!(
+        Value::Response(ResponseData {
+            committed: false,
+            data: Box::new(Value::Tuple(
+                TupleData::from_data(vec![
+                    (
+                        "error_code".into(),
+                        Value::UInt(PrincipalConstructErrorCode::VERSION_BYTE as u128)
+                    ),
+                    (
+                        "value".into(),
+                        Value::some(create_principal_from_strings(
+                            "1a",
+                            "0102030405060708091011121314151617181920",
+                            None
+                        ))
+                        .expect("Value::some failed.")
+                    ),
+                ])
+                .expect("FAIL: Failed to initialize tuple."),
+            )),
+        }),
+        run("(principal-construct? 0x1a 0x0102030405060708091011121314151617181920)")
+    );
+
+    // `is-standard` follows the custom versions, for standard and contract principals.
+    assert_eq!(
+        Value::Bool(true),
+        run("(is-standard (unwrap-panic (principal-construct? 0x05 0x0102030405060708091011121314151617181920)))")
+    );
+    assert_eq!(
+        Value::Bool(true),
+        run(
+            r#"(is-standard (unwrap-panic (principal-construct? 0x06 0x0102030405060708091011121314151617181920 "foo")))"#
+        )
+    );
+    assert_eq!(
+        Value::Bool(false),
+        run("(is-standard 'STB44HYPYAT2BB2QE513NSP81HTMYWBJP02HPGK6)")
+    );
+    assert_eq!(
+        Value::Bool(false),
+        run("(is-standard 'SP3X6QWWETNBZWGBK6DRGTR1KX50S74D3433WDGJY)")
+    );
+
+    // `principal-destruct?` takes the ok branch only for the custom versions.
+    assert_eq!(
+        Value::Response(ResponseData {
+            committed: true,
+            data: Box::new(create_principal_destruct_tuple_from_strings(
+                "06",
+                "0102030405060708091011121314151617181920",
+                None
+            ))
+        }),
+        run("(principal-destruct? (unwrap-panic (principal-construct? 0x06 0x0102030405060708091011121314151617181920)))")
+    );
+    assert_eq!(
+        Value::Response(ResponseData {
+            committed: false,
+            data: Box::new(create_principal_destruct_tuple_from_strings(
+                "1a",
+                "fa6bf38ed557fe417333710d6033e9419391a320",
+                None
+            ))
+        }),
+        run("(principal-destruct? 'ST3X6QWWETNBZWGBK6DRGTR1KX50S74D3425Q1TPK)")
+    );
+}
+
+#[test]
+// `principal-construct?` is the inverse of `principal-destruct?`: rebuilding a principal from its
+// destructed parts yields the same principal, through the same response channel.
+fn test_principal_destruct_construct_round_trip() {
+    let principals = [
+        "SP3X6QWWETNBZWGBK6DRGTR1KX50S74D3433WDGJY",
+        "SM3X6QWWETNBZWGBK6DRGTR1KX50S74D341M9C5X7",
+        "ST3X6QWWETNBZWGBK6DRGTR1KX50S74D3425Q1TPK",
+        "SN3X6QWWETNBZWGBK6DRGTR1KX50S74D340JWTSC7",
+        "SP3X6QWWETNBZWGBK6DRGTR1KX50S74D3433WDGJY.foo",
+        "SM3X6QWWETNBZWGBK6DRGTR1KX50S74D341M9C5X7.foo",
+        "ST3X6QWWETNBZWGBK6DRGTR1KX50S74D3425Q1TPK.foo",
+        "SN3X6QWWETNBZWGBK6DRGTR1KX50S74D340JWTSC7.foo",
+    ];
+
+    for principal in principals {
+        for mainnet in [true, false] {
+            // Destruct through whichever channel applies, then rebuild with or without the name.
+            let input = format!(
+                r#"(let ((parts (match (principal-destruct? '{}) ok-parts ok-parts err-parts err-parts)))
+                    (match (get name parts)
+                        name (principal-construct? (get version parts) (get hash-bytes parts) name)
+                        (principal-construct? (get version parts) (get hash-bytes parts))))"#,
+                principal
+            );
+            let rebuilt = execute_with_parameters(
+                &input,
+                ClarityVersion::Clarity2,
+                StacksEpochId::Epoch21,
+                ASTRules::PrecheckSize,
+                mainnet,
+            )
+            .unwrap()
+            .unwrap();
+
+            let principal_value = Value::Principal(PrincipalData::parse(principal).unwrap());
+            let on_network = principal.starts_with("SP") || principal.starts_with("SM");
+            let expected = if on_network == mainnet {
+                Value::okay(principal_value).unwrap()
+            } else {
+                // Foreign-network principals come back through the err channel, still intact.
+                Value::error(Value::Tuple(
+                    TupleData::from_data(vec![
+                        (
+                            "error_code".into(),
+                            Value::UInt(PrincipalConstructErrorCode::VERSION_BYTE as u128),
+                        ),
+                        ("value".into(), Value::some(principal_value).unwrap()),
+                    ])
+                    .expect("FAIL: Failed to initialize tuple."),
+                ))
+                .unwrap()
+            };
+            assert_eq!(expected, rebuilt, "round trip of {} failed", principal);
+        }
+    }
+}
+
+#[test]
+// A contract deployed in one snippet stores principals built by `principal-construct?`, and later
+// snippets read them back through the same backing store.
+fn test_principal_construct_into_map_storage() {
+    let mut store = MemoryBackingStore::new();
+    let mut env = TestEnvironment::new(
+        &mut store,
+        ClarityVersion::Clarity2,
+        StacksEpochId::Epoch21,
+        LimitedCostTracker::new_free(),
+        false,
+    );
+
+    let contract = r#"
+        (define-map allowed principal bool)
+        (define-public (allow (version (buff 1)) (hash-bytes (buff 20)))
+            (let ((who (unwrap! (principal-construct? version hash-bytes) (err u1))))
+                (ok (map-set allowed who true))))
+        (define-read-only (is-allowed (who principal))
+            (default-to false (map-get? allowed who)))
+    "#;
+    env.deploy_contract("allow-list", contract).unwrap();
+
+    // A testnet principal is constructed and stored.
+    assert_eq!(
+        Value::okay(Value::Bool(true)).unwrap(),
+        env.eval(
+            "(contract-call? .allow-list allow 0x1a 0xfa6bf38ed557fe417333710d6033e9419391a320)"
+        )
+        .unwrap()
+    );
+    // A mainnet principal is rejected on testnet, and nothing is stored.
+    assert_eq!(
+        Value::error(Value::UInt(1)).unwrap(),
+        env.eval(
+            "(contract-call? .allow-list allow 0x16 0xfa6bf38ed557fe417333710d6033e9419391a320)"
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        Value::Bool(true),
+        env.eval(
+            "(contract-call? .allow-list is-allowed 'ST3X6QWWETNBZWGBK6DRGTR1KX50S74D3425Q1TPK)"
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        Value::Bool(false),
+        env.eval(
+            "(contract-call? .allow-list is-allowed 'SP3X6QWWETNBZWGBK6DRGTR1KX50S74D3433WDGJY)"
+        )
+        .unwrap()
+    );
 }